@@ -1,6 +1,11 @@
 #[cfg(feature = "alloc")]
 use alloc::vec::Vec;
 
+/// The maximum number of bytes [read_vec] asks `Vec::reserve` for in one go while growing its
+/// result.
+#[cfg(feature = "alloc")]
+const READ_VEC_GROWTH_STEP: usize = 1024;
+
 /// A target that can be read from. This is similar to `std::io::Read`, but the std trait is not
 /// available in `#![no_std]` projects.
 ///
@@ -41,14 +46,222 @@ pub trait CoreRead<'a> {
     fn read_range(&mut self, len: usize) -> Result<&'a [u8], Self::Error>;
 
     /// Read an owned vec from this reader.
+    ///
+    /// Capacity is requested in capped increments of [READ_VEC_GROWTH_STEP] as bytes actually
+    /// arrive (`Vec` may round each request up as part of its usual amortized growth), rather
+    /// than allocating `len` bytes up front. This keeps a corrupt or hostile
+    /// length prefix from triggering a single multi-gigabyte allocation before a single byte
+    /// has been validated.
     #[cfg(feature = "alloc")]
     fn read_vec(&mut self, len: usize) -> Result<Vec<u8>, Self::Error> {
-        let mut vec = Vec::with_capacity(len);
-        for _ in 0..len {
-            vec.push(self.read()?);
+        let mut vec = Vec::new();
+        let mut read = 0;
+        while read < len {
+            let chunk = core::cmp::min(READ_VEC_GROWTH_STEP, len - read);
+            vec.reserve(chunk);
+            for _ in 0..chunk {
+                vec.push(self.read()?);
+            }
+            read += chunk;
         }
         Ok(vec)
     }
+
+    /// Read an owned vec from this reader, rejecting `len` upfront when it exceeds `max`.
+    ///
+    /// This lets decoders of untrusted input bound memory use without first pre-scanning the
+    /// length prefix.
+    #[cfg(feature = "alloc")]
+    fn read_vec_capped(&mut self, len: usize, max: usize) -> Result<Vec<u8>, Self::Error>
+    where
+        Self::Error: From<CapacityExceeded>,
+    {
+        if len > max {
+            return Err(CapacityExceeded.into());
+        }
+        self.read_vec(len)
+    }
+
+    /// Read a fixed-size array of `N` bytes from this reader.
+    ///
+    /// This is auto-implemented on top of [read_range], copying the returned slice into a
+    /// freshly initialized `[0u8; N]`. Implementors that can read directly into a stack buffer
+    /// are free to override this for a more efficient implementation.
+    ///
+    /// Returns `Self::Error` (via [LengthMismatch]) instead of panicking if a non-conforming
+    /// [read_range] implementation returns a slice that isn't exactly `N` bytes long.
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], Self::Error>
+    where
+        Self::Error: From<LengthMismatch>,
+    {
+        let slice = self.read_range(N)?;
+        if slice.len() != N {
+            return Err(LengthMismatch.into());
+        }
+        let mut array = [0u8; N];
+        array.copy_from_slice(slice);
+        Ok(array)
+    }
+
+    /// Read a little-endian `u16`, built on top of [read_array].
+    fn read_u16(&mut self) -> Result<u16, Self::Error>
+    where
+        Self::Error: From<LengthMismatch>,
+    {
+        self.read_array::<2>().map(u16::from_le_bytes)
+    }
+
+    /// Read a big-endian `u16`, built on top of [read_array].
+    fn read_u16_be(&mut self) -> Result<u16, Self::Error>
+    where
+        Self::Error: From<LengthMismatch>,
+    {
+        self.read_array::<2>().map(u16::from_be_bytes)
+    }
+
+    /// Read a little-endian `u32`, built on top of [read_array].
+    fn read_u32(&mut self) -> Result<u32, Self::Error>
+    where
+        Self::Error: From<LengthMismatch>,
+    {
+        self.read_array::<4>().map(u32::from_le_bytes)
+    }
+
+    /// Read a big-endian `u32`, built on top of [read_array].
+    fn read_u32_be(&mut self) -> Result<u32, Self::Error>
+    where
+        Self::Error: From<LengthMismatch>,
+    {
+        self.read_array::<4>().map(u32::from_be_bytes)
+    }
+
+    /// Read a little-endian `u64`, built on top of [read_array].
+    fn read_u64(&mut self) -> Result<u64, Self::Error>
+    where
+        Self::Error: From<LengthMismatch>,
+    {
+        self.read_array::<8>().map(u64::from_le_bytes)
+    }
+
+    /// Read a big-endian `u64`, built on top of [read_array].
+    fn read_u64_be(&mut self) -> Result<u64, Self::Error>
+    where
+        Self::Error: From<LengthMismatch>,
+    {
+        self.read_array::<8>().map(u64::from_be_bytes)
+    }
+
+    /// Read a little-endian `u128`, built on top of [read_array].
+    fn read_u128(&mut self) -> Result<u128, Self::Error>
+    where
+        Self::Error: From<LengthMismatch>,
+    {
+        self.read_array::<16>().map(u128::from_le_bytes)
+    }
+
+    /// Read a big-endian `u128`, built on top of [read_array].
+    fn read_u128_be(&mut self) -> Result<u128, Self::Error>
+    where
+        Self::Error: From<LengthMismatch>,
+    {
+        self.read_array::<16>().map(u128::from_be_bytes)
+    }
+
+    /// Read a little-endian `usize`, encoded on the wire as a `u64` to stay portable across
+    /// targets with different pointer widths.
+    ///
+    /// Returns `Self::Error` (via [UsizeOverflow]) rather than silently truncating when the
+    /// decoded value does not fit in this target's `usize`, e.g. a 32-bit target reading a
+    /// value larger than `u32::MAX`.
+    fn read_usize(&mut self) -> Result<usize, Self::Error>
+    where
+        Self::Error: From<LengthMismatch> + From<UsizeOverflow>,
+    {
+        usize::try_from(self.read_u64()?).map_err(|_| UsizeOverflow.into())
+    }
+
+    /// Read a big-endian `usize`, encoded on the wire as a `u64` to stay portable across targets
+    /// with different pointer widths.
+    ///
+    /// Returns `Self::Error` (via [UsizeOverflow]) rather than silently truncating when the
+    /// decoded value does not fit in this target's `usize`, e.g. a 32-bit target reading a
+    /// value larger than `u32::MAX`.
+    fn read_usize_be(&mut self) -> Result<usize, Self::Error>
+    where
+        Self::Error: From<LengthMismatch> + From<UsizeOverflow>,
+    {
+        usize::try_from(self.read_u64_be()?).map_err(|_| UsizeOverflow.into())
+    }
+
+    /// Read a single byte and interpret it as a `bool`.
+    ///
+    /// Only `0` and `1` are accepted; any other value is rejected with a dedicated error rather
+    /// than being silently coerced to `true`.
+    fn read_bool(&mut self) -> Result<bool, Self::Error>
+    where
+        Self::Error: From<InvalidBoolValue>,
+    {
+        match self.read()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(InvalidBoolValue.into()),
+        }
+    }
+
+    /// Read `len` bytes, first consuming whatever leading padding is necessary so the returned
+    /// slice's start address satisfies `align`.
+    ///
+    /// This is what makes constant-time, zero-copy deserialization of `#[repr(C)]`/Pod
+    /// structures possible: callers can cast the returned slice directly to `&T` instead of
+    /// copying field-by-field, as long as the reader's underlying buffer is itself persistent
+    /// (as required by this trait).
+    ///
+    /// `align` must be non-zero; passing `0` returns `Self::Error` (via [MisalignedData]) rather
+    /// than panicking on the modulo below.
+    ///
+    /// The default implementation probes the current position with a zero-length [read_range],
+    /// then skips the padding needed to reach `align` before reading `len` bytes. Skipping that
+    /// padding can itself fail if not enough bytes remain, in which case the underlying
+    /// [read_range] error (not [MisalignedData]) propagates, the same as any other short read.
+    /// The start address of the final slice is checked as well, and [MisalignedData] is returned
+    /// if it does not satisfy `align`, e.g. for a reader whose slices do not all come from one
+    /// contiguous buffer.
+    fn read_range_aligned(&mut self, len: usize, align: usize) -> Result<&'a [u8], Self::Error>
+    where
+        Self::Error: From<MisalignedData>,
+    {
+        if align == 0 {
+            return Err(MisalignedData.into());
+        }
+        let probe = self.read_range(0)?;
+        let misaligned_by = probe.as_ptr() as usize % align;
+        if misaligned_by > 0 {
+            self.read_range(align - misaligned_by)?;
+        }
+        let slice = self.read_range(len)?;
+        if slice.as_ptr() as usize % align != 0 {
+            return Err(MisalignedData.into());
+        }
+        Ok(slice)
+    }
+
+    /// Look at the next byte in the buffer without consuming it.
+    ///
+    /// Unlike [read], a subsequent call to [read] or [read_range] will still see the byte
+    /// returned by this method.
+    fn peek(&self) -> Result<u8, Self::Error>;
+
+    /// Returns `true` if there is at least one more byte left to read.
+    fn has_more_bytes(&self) -> bool;
+
+    /// Check that at least `num_bytes` are still available to read, without consuming anything.
+    ///
+    /// This allows callers such as length-prefixed collection decoders to fail fast with a
+    /// single, clean error instead of running out of data part-way through a loop.
+    ///
+    /// The default implementation is left abstract because a purely sequential reader has no
+    /// way of knowing how many bytes remain.
+    fn check_eor(&self, num_bytes: usize) -> Result<(), Self::Error>;
 }
 
 impl<'a> CoreRead<'a> for &'a [u8] {
@@ -62,6 +275,21 @@ impl<'a> CoreRead<'a> for &'a [u8] {
         *self = &self[len..];
         Ok(result)
     }
+
+    fn peek(&self) -> Result<u8, Self::Error> {
+        self.first().copied().ok_or(SliceReadError::EndOfSlice)
+    }
+
+    fn has_more_bytes(&self) -> bool {
+        !self.is_empty()
+    }
+
+    fn check_eor(&self, num_bytes: usize) -> Result<(), Self::Error> {
+        if num_bytes > self.len() {
+            return Err(SliceReadError::EndOfSlice);
+        }
+        Ok(())
+    }
 }
 
 /// An error that is thrown when reading from a slice.
@@ -69,4 +297,84 @@ impl<'a> CoreRead<'a> for &'a [u8] {
 pub enum SliceReadError {
     /// Tried reading more bytes than the slice contains.
     EndOfSlice,
+    /// The requested length exceeded the cap passed to [read_vec_capped].
+    CapacityExceeded,
+    /// [read_bool] encountered a byte other than `0` or `1`.
+    InvalidBoolValue,
+    /// [read_range_aligned] could not produce a slice that satisfies the requested alignment.
+    MisalignedData,
+    /// A `read_range` implementation returned a slice of the wrong length for [read_array].
+    LengthMismatch,
+    /// [read_usize]/[read_usize_be] decoded a value that doesn't fit in this target's `usize`.
+    UsizeOverflow,
+}
+
+/// A marker error indicating that a requested length exceeded a caller-supplied cap.
+///
+/// Reader error types that want to support [read_vec_capped] need to implement
+/// `From<CapacityExceeded>`.
+#[cfg(feature = "alloc")]
+#[derive(Debug)]
+pub struct CapacityExceeded;
+
+#[cfg(feature = "alloc")]
+impl From<CapacityExceeded> for SliceReadError {
+    fn from(_: CapacityExceeded) -> Self {
+        SliceReadError::CapacityExceeded
+    }
+}
+
+/// A marker error indicating that [read_bool] read a byte other than `0` or `1`.
+///
+/// Reader error types that want to support [read_bool] need to implement
+/// `From<InvalidBoolValue>`.
+#[derive(Debug)]
+pub struct InvalidBoolValue;
+
+impl From<InvalidBoolValue> for SliceReadError {
+    fn from(_: InvalidBoolValue) -> Self {
+        SliceReadError::InvalidBoolValue
+    }
+}
+
+/// A marker error indicating that [read_range_aligned] could not satisfy the requested
+/// alignment.
+///
+/// Reader error types that want to support [read_range_aligned] need to implement
+/// `From<MisalignedData>`.
+#[derive(Debug)]
+pub struct MisalignedData;
+
+impl From<MisalignedData> for SliceReadError {
+    fn from(_: MisalignedData) -> Self {
+        SliceReadError::MisalignedData
+    }
+}
+
+/// A marker error indicating that a `read_range` implementation returned a slice of the wrong
+/// length for [read_array].
+///
+/// Reader error types that want to support [read_array] (and anything built on top of it, such
+/// as the typed integer readers) need to implement `From<LengthMismatch>`.
+#[derive(Debug)]
+pub struct LengthMismatch;
+
+impl From<LengthMismatch> for SliceReadError {
+    fn from(_: LengthMismatch) -> Self {
+        SliceReadError::LengthMismatch
+    }
+}
+
+/// A marker error indicating that [read_usize]/[read_usize_be] decoded a value that doesn't fit
+/// in this target's `usize`.
+///
+/// Reader error types that want to support [read_usize]/[read_usize_be] need to implement
+/// `From<UsizeOverflow>`.
+#[derive(Debug)]
+pub struct UsizeOverflow;
+
+impl From<UsizeOverflow> for SliceReadError {
+    fn from(_: UsizeOverflow) -> Self {
+        SliceReadError::UsizeOverflow
+    }
 }
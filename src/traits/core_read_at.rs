@@ -0,0 +1,84 @@
+/// A target that supports random-access reads at an arbitrary offset, in addition to (or instead
+/// of) the purely sequential reads of [`CoreRead`](crate::traits::core_read::CoreRead).
+///
+/// This is useful for formats that store internal offsets (section tables, index structures) and
+/// for zero-copy memory-mapped sources, where jumping directly to a later structure is preferable
+/// to consuming everything that precedes it.
+///
+/// This trait is auto-implemented for `&'a [u8]`.
+pub trait CoreReadAt<'a> {
+    /// The error that this reader can encounter
+    type Error: core::fmt::Debug;
+
+    /// Read `len` bytes starting at `offset`, without consuming anything.
+    ///
+    /// Because reads are done in-place, the value returned MUST be a reference to a persistent
+    /// buffer, the same as [`CoreRead::read_range`](crate::traits::core_read::CoreRead::read_range).
+    fn read_range_at(&self, offset: usize, len: usize) -> Result<&'a [u8], Self::Error>;
+
+    /// Read a fixed-size array of `N` bytes starting at `offset`.
+    ///
+    /// This is auto-implemented on top of [read_range_at], copying the returned slice into a
+    /// freshly initialized `[0u8; N]`.
+    ///
+    /// Returns `Self::Error` (via [LengthMismatch]) instead of panicking if a non-conforming
+    /// [read_range_at] implementation returns a slice that isn't exactly `N` bytes long.
+    fn read_at<const N: usize>(&self, offset: usize) -> Result<[u8; N], Self::Error>
+    where
+        Self::Error: From<LengthMismatch>,
+    {
+        let slice = self.read_range_at(offset, N)?;
+        if slice.len() != N {
+            return Err(LengthMismatch.into());
+        }
+        let mut array = [0u8; N];
+        array.copy_from_slice(slice);
+        Ok(array)
+    }
+
+    /// The total number of bytes available in this reader.
+    fn len(&self) -> usize;
+
+    /// Returns `true` if this reader holds no bytes at all.
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<'a> CoreReadAt<'a> for &'a [u8] {
+    type Error = SliceReadAtError;
+
+    fn read_range_at(&self, offset: usize, len: usize) -> Result<&'a [u8], Self::Error> {
+        let end = offset.checked_add(len).ok_or(SliceReadAtError::OutOfBounds)?;
+        if end > self.len() {
+            return Err(SliceReadAtError::OutOfBounds);
+        }
+        Ok(&self[offset..end])
+    }
+
+    fn len(&self) -> usize {
+        (*self).len()
+    }
+}
+
+/// An error that is thrown when reading from a slice at a given offset.
+#[derive(Debug)]
+pub enum SliceReadAtError {
+    /// The requested offset and length fall outside of the slice's bounds.
+    OutOfBounds,
+    /// A `read_range_at` implementation returned a slice of the wrong length for [read_at].
+    LengthMismatch,
+}
+
+/// A marker error indicating that a `read_range_at` implementation returned a slice of the
+/// wrong length for [read_at].
+///
+/// Reader error types that want to support [read_at] need to implement `From<LengthMismatch>`.
+#[derive(Debug)]
+pub struct LengthMismatch;
+
+impl From<LengthMismatch> for SliceReadAtError {
+    fn from(_: LengthMismatch) -> Self {
+        SliceReadAtError::LengthMismatch
+    }
+}